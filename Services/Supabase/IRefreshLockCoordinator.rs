@@ -0,0 +1,22 @@
+namespace SubashaVentures.Services.Supabase;
+
+/// <summary>
+/// Cross-tab/circuit mutual exclusion for session refresh. A plain
+/// localStorage read-then-write can't provide this: two tabs can each read
+/// "unlocked" before either writes, so both end up believing they hold the
+/// lock. An implementation must make the acquire a single atomic operation
+/// </summary>
+public interface IRefreshLockCoordinator
+{
+    /// <summary>
+    /// Attempt to atomically acquire the named lock for <paramref name="ownerId"/>.
+    /// Returns false if the lock is not acquired. An implementation may wait up to
+    /// <paramref name="ttl"/> for a current holder to release it before returning
+    /// </summary>
+    Task<bool> TryAcquireAsync(string lockName, string ownerId, TimeSpan ttl);
+
+    /// <summary>
+    /// Release the named lock if <paramref name="ownerId"/> currently holds it
+    /// </summary>
+    Task ReleaseAsync(string lockName, string ownerId);
+}
@@ -0,0 +1,91 @@
+namespace SubashaVentures.Services.Supabase;
+
+/// <summary>
+/// Base type for classified session-refresh failures, distinguishing
+/// recoverable transient errors from terminal ones that require a full re-login
+/// </summary>
+public abstract class SessionException : Exception
+{
+    protected SessionException(string message) : base(message)
+    {
+    }
+
+    protected SessionException(string message, Exception innerException) : base(message, innerException)
+    {
+    }
+}
+
+/// <summary>
+/// The refresh token was already consumed by another refresh - terminal,
+/// the session must be cleared and the user re-authenticated
+/// </summary>
+public sealed class RefreshTokenReusedException : SessionException
+{
+    public RefreshTokenReusedException(string message) : base(message)
+    {
+    }
+
+    public RefreshTokenReusedException(string message, Exception innerException) : base(message, innerException)
+    {
+    }
+}
+
+/// <summary>
+/// Gotrue rejected the refresh/grant outright (e.g. revoked or expired
+/// refresh token) - terminal, the session must be cleared
+/// </summary>
+public sealed class InvalidGrantException : SessionException
+{
+    public InvalidGrantException(string message) : base(message)
+    {
+    }
+
+    public InvalidGrantException(string message, Exception innerException) : base(message, innerException)
+    {
+    }
+}
+
+/// <summary>
+/// A recoverable failure (network blip, timeout, 5xx) - the stored tokens
+/// are still good and a later retry can succeed
+/// </summary>
+public sealed class TransientRefreshException : SessionException
+{
+    public TransientRefreshException(string message) : base(message)
+    {
+    }
+
+    public TransientRefreshException(string message, Exception innerException) : base(message, innerException)
+    {
+    }
+}
+
+/// <summary>
+/// Maps raw exceptions/Gotrue error messages onto the <see cref="SessionException"/>
+/// hierarchy so callers can tell a terminal failure from a transient one
+/// </summary>
+public static class SessionErrorClassifier
+{
+    public static SessionException Classify(Exception ex)
+    {
+        var message = ex.Message ?? string.Empty;
+
+        // Check reuse first - Gotrue's actual reuse error text (e.g. "Invalid Refresh
+        // Token: Already Used") contains "invalid refresh token", so the broader
+        // invalid-grant check below would otherwise shadow this one
+        if (message.Contains("refresh_token_already_used", StringComparison.OrdinalIgnoreCase) ||
+            message.Contains("already used", StringComparison.OrdinalIgnoreCase) ||
+            message.Contains("already been used", StringComparison.OrdinalIgnoreCase))
+        {
+            return new RefreshTokenReusedException(message, ex);
+        }
+
+        if (message.Contains("invalid_grant", StringComparison.OrdinalIgnoreCase) ||
+            message.Contains("invalid refresh token", StringComparison.OrdinalIgnoreCase))
+        {
+            return new InvalidGrantException(message, ex);
+        }
+
+        return new TransientRefreshException(message, ex);
+    }
+}
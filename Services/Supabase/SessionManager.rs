@@ -14,22 +14,77 @@ public class SessionManager
 {
     private const string AccessTokenKey = "supabase_access_token";
     private const string RefreshTokenKey = "supabase_refresh_token";
-    private const string SessionExpiryKey = "supabase_session_expiry";
-    
+    private const string SessionStartedAtKey = "supabase_session_started_at";
+    private const string LastActivityAtKey = "supabase_last_activity_at";
+
     private readonly IBlazorAppLocalStorageService _localStorage;
     private readonly ILogger<SessionManager> _logger;
-    
+
     // Refresh lock - prevents concurrent refresh attempts
     private readonly SemaphoreSlim _refreshLock = new(1, 1);
     private DateTime _lastRefreshAttempt = DateTime.MinValue;
     private const int RefreshCooldownSeconds = 10; // Supabase's reuse interval
-    
+
+    // Single-flight refresh - callers that arrive while a refresh is already
+    // in progress await the same task instead of getting null back
+    private Task<Session?>? _inFlightRefresh;
+
+    // Result of the most recently completed refresh - a caller that arrives
+    // during the cooldown window (after _inFlightRefresh has already cleared)
+    // gets this instead of being told the just-refreshed session is a failure
+    private Session? _lastCompletedRefresh;
+
+    // Session lifetime - a session is abandoned once it has been idle longer
+    // than the sliding window, or has existed longer than the absolute cap,
+    // regardless of how recently the access token itself was refreshed
+    private readonly TimeSpan _slidingIdleWindow;
+    private readonly TimeSpan _absoluteSessionLifetime;
+    private static readonly TimeSpan DefaultSlidingIdleWindow = TimeSpan.FromMinutes(30);
+    private static readonly TimeSpan DefaultAbsoluteSessionLifetime = TimeSpan.FromDays(7);
+
+    // Proactive background refresh loop - renews the token before callers ever
+    // notice it's close to expiry, instead of waiting on a reactive check
+    private CancellationTokenSource? _autoRefreshCts;
+    private Task? _autoRefreshTask;
+    private const int AutoRefreshBaseBackoffSeconds = 5;
+    private const int AutoRefreshMaxBackoffSeconds = 300;
+    private const int AutoRefreshMaxConsecutiveFailures = 5;
+
+    /// <summary>
+    /// Raised when the auto-refresh loop gives up, either after repeated failures
+    /// or because the stored session is past its idle/absolute lifetime -
+    /// the UI should redirect to login
+    /// </summary>
+    public event Action? SessionExpired;
+
+    /// <summary>
+    /// Raised when a refresh fails terminally (e.g. the refresh token was already
+    /// used or rejected outright) and the session has been cleared as a result
+    /// </summary>
+    public event Action<SessionException>? SessionInvalidated;
+
+    // Cross-tab/circuit coordination - a SemaphoreSlim only serializes refreshes
+    // within one circuit, so an atomic cross-tab lock stops two tabs from each
+    // using the same refresh token at once
+    private readonly IRefreshLockCoordinator _refreshLockCoordinator;
+    private const string RefreshLockName = "supabase_refresh_lock";
+    private static readonly TimeSpan RefreshLockTtl = TimeSpan.FromSeconds(30);
+    private static readonly TimeSpan CrossTabPollInterval = TimeSpan.FromMilliseconds(250);
+    private const int CrossTabPollMaxAttempts = 40; // ~10s, bounded by RefreshLockTtl
+    private readonly string _instanceId = Guid.NewGuid().ToString();
+
     public SessionManager(
         IBlazorAppLocalStorageService localStorage,
-        ILogger<SessionManager> logger)
+        ILogger<SessionManager> logger,
+        IRefreshLockCoordinator refreshLockCoordinator,
+        TimeSpan? slidingIdleWindow = null,
+        TimeSpan? absoluteSessionLifetime = null)
     {
         _localStorage = localStorage;
         _logger = logger;
+        _refreshLockCoordinator = refreshLockCoordinator;
+        _slidingIdleWindow = slidingIdleWindow ?? DefaultSlidingIdleWindow;
+        _absoluteSessionLifetime = absoluteSessionLifetime ?? DefaultAbsoluteSessionLifetime;
     }
 
     /// <summary>
@@ -41,7 +96,8 @@ public class SessionManager
         {
             var accessToken = await _localStorage.GetItemAsync<string>(AccessTokenKey);
             var refreshToken = await _localStorage.GetItemAsync<string>(RefreshTokenKey);
-            var expiryString = await _localStorage.GetItemAsync<string>(SessionExpiryKey);
+            var startedAtString = await _localStorage.GetItemAsync<string>(SessionStartedAtKey);
+            var lastActivityString = await _localStorage.GetItemAsync<string>(LastActivityAtKey);
 
             if (string.IsNullOrEmpty(accessToken) || string.IsNullOrEmpty(refreshToken))
             {
@@ -52,18 +108,42 @@ public class SessionManager
                 return null;
             }
 
-            DateTime? expiry = null;
-            if (!string.IsNullOrEmpty(expiryString) && 
-                DateTime.TryParse(expiryString, out var parsedExpiry))
+            // Trust the exp claim inside the access token itself rather than the
+            // separately stored expiry string, which can drift or be tampered with
+            var tokenExpiry = TokenInspector.GetExpiry(accessToken);
+            if (TokenInspector.HasExpired(tokenExpiry))
+            {
+                await MID_HelperFunctions.DebugMessageAsync(
+                    "⚠️ Stored access token is expired or malformed - clearing session",
+                    LogLevel.Warning
+                );
+                await ClearSessionAsync();
+                return null;
+            }
+
+            var expiry = tokenExpiry;
+
+            DateTime? sessionStartedAt = null;
+            if (!string.IsNullOrEmpty(startedAtString) &&
+                DateTime.TryParse(startedAtString, null, System.Globalization.DateTimeStyles.RoundtripKind, out var parsedStartedAt))
+            {
+                sessionStartedAt = parsedStartedAt;
+            }
+
+            DateTime? lastActivityAt = null;
+            if (!string.IsNullOrEmpty(lastActivityString) &&
+                DateTime.TryParse(lastActivityString, null, System.Globalization.DateTimeStyles.RoundtripKind, out var parsedLastActivity))
             {
-                expiry = parsedExpiry;
+                lastActivityAt = parsedLastActivity;
             }
 
             return new StoredSession
             {
                 AccessToken = accessToken,
                 RefreshToken = refreshToken,
-                ExpiresAt = expiry
+                ExpiresAt = expiry,
+                SessionStartedAt = sessionStartedAt,
+                LastActivityAt = lastActivityAt
             };
         }
         catch (Exception ex)
@@ -80,9 +160,42 @@ public class SessionManager
     {
         try
         {
+            // Source the canonical expiry from the access token's own exp claim rather
+            // than Session.ExpiresAt(), so stored state can't drift from it. Reject up
+            // front rather than falling back to Session.ExpiresAt() when it's missing or
+            // already past - GetStoredSessionAsync applies this same check on every read,
+            // so a token that wouldn't survive that check shouldn't be stored as valid either
+            var canonicalExpiry = TokenInspector.GetExpiry(session.AccessToken);
+            if (TokenInspector.HasExpired(canonicalExpiry))
+            {
+                _logger.LogError(
+                    "Refusing to store session with an unparseable or already-expired access token");
+                return;
+            }
+
             await _localStorage.SetItemAsync(AccessTokenKey, session.AccessToken);
             await _localStorage.SetItemAsync(RefreshTokenKey, session.RefreshToken ?? "");
-            await _localStorage.SetItemAsync(SessionExpiryKey, session.ExpiresAt().ToString("o"));
+
+            // Preserve the original SessionStartedAt across refreshes - only a fresh
+            // login should reset the absolute-lifetime clock
+            var startedAtString = await _localStorage.GetItemAsync<string>(SessionStartedAtKey);
+            var isFreshLogin = string.IsNullOrEmpty(startedAtString) ||
+                                !DateTime.TryParse(startedAtString, out _);
+            var sessionStartedAt = !isFreshLogin && DateTime.TryParse(startedAtString, out var existingStartedAt)
+                ? existingStartedAt
+                : DateTime.UtcNow;
+
+            await _localStorage.SetItemAsync(SessionStartedAtKey, sessionStartedAt.ToString("o"));
+
+            // Only stamp LastActivityAt on a fresh login, where there's no recorded
+            // activity yet to fall back on. A token refresh (proactive or reactive)
+            // is not itself user activity, and IsExpired relies on that distinction
+            // to ever catch an idle session - only RegisterActivityAsync should bump
+            // this afterwards.
+            if (isFreshLogin)
+            {
+                await _localStorage.SetItemAsync(LastActivityAtKey, DateTime.UtcNow.ToString("o"));
+            }
 
             await MID_HelperFunctions.DebugMessageAsync(
                 $"✅ Session stored (expires: {session.ExpiresAt():yyyy-MM-dd HH:mm:ss})",
@@ -95,6 +208,21 @@ public class SessionManager
         }
     }
 
+    /// <summary>
+    /// Record user activity, resetting the sliding idle window
+    /// </summary>
+    public async Task RegisterActivityAsync()
+    {
+        try
+        {
+            await _localStorage.SetItemAsync(LastActivityAtKey, DateTime.UtcNow.ToString("o"));
+        }
+        catch (Exception ex)
+        {
+            _logger.LogError(ex, "Error registering session activity");
+        }
+    }
+
     /// <summary>
     /// Clear stored session
     /// </summary>
@@ -104,8 +232,9 @@ public class SessionManager
         {
             await _localStorage.RemoveItemAsync(AccessTokenKey);
             await _localStorage.RemoveItemAsync(RefreshTokenKey);
-            await _localStorage.RemoveItemAsync(SessionExpiryKey);
-            
+            await _localStorage.RemoveItemAsync(SessionStartedAtKey);
+            await _localStorage.RemoveItemAsync(LastActivityAtKey);
+
             await MID_HelperFunctions.DebugMessageAsync(
                 "✅ Session cleared",
                 LogLevel.Debug
@@ -118,24 +247,76 @@ public class SessionManager
     }
 
     /// <summary>
-    /// Check if session needs refresh (within 5 minutes of expiry)
+    /// Check if session needs refresh (within 5 minutes of expiry and still
+    /// within the sliding idle window - an idle-out session is handled by IsExpired instead)
     /// </summary>
-    public bool ShouldRefresh(DateTime? expiresAt)
+    public bool ShouldRefresh(StoredSession? session)
     {
-        if (expiresAt == null) return true;
-        
-        var timeUntilExpiry = expiresAt.Value - DateTime.UtcNow;
-        return timeUntilExpiry.TotalMinutes < 5;
+        if (session?.ExpiresAt == null) return true;
+
+        var timeUntilExpiry = session.ExpiresAt.Value - DateTime.UtcNow;
+        if (timeUntilExpiry.TotalMinutes >= 5) return false;
+
+        if (session.LastActivityAt.HasValue &&
+            DateTime.UtcNow - session.LastActivityAt.Value > _slidingIdleWindow)
+        {
+            return false;
+        }
+
+        return true;
+    }
+
+    /// <summary>
+    /// Check if the session has outlived its absolute lifetime or gone idle past
+    /// the sliding window, meaning it can no longer be refreshed and requires a full re-login
+    /// </summary>
+    public bool IsExpired(StoredSession? session)
+    {
+        if (session == null) return true;
+
+        var now = DateTime.UtcNow;
+
+        if (session.SessionStartedAt.HasValue &&
+            now - session.SessionStartedAt.Value > _absoluteSessionLifetime)
+        {
+            return true;
+        }
+
+        if (session.LastActivityAt.HasValue &&
+            now - session.LastActivityAt.Value > _slidingIdleWindow)
+        {
+            return true;
+        }
+
+        return false;
     }
 
     /// <summary>
     /// Execute refresh with lock to prevent concurrent attempts
     /// CRITICAL: This prevents "refresh_token_already_used" errors
     /// </summary>
+    /// <exception cref="SessionException">
+    /// Thrown when refreshFunc fails, classified as terminal (session already
+    /// cleared, see <see cref="SessionInvalidated"/>) or transient (stored
+    /// tokens left intact for a later retry)
+    /// </exception>
     public async Task<Session?> ExecuteRefreshWithLockAsync(
         Func<Task<Session?>> refreshFunc)
     {
-        // Check cooldown period
+        // Piggyback on a refresh that's already running rather than bailing out
+        var inFlight = _inFlightRefresh;
+        if (inFlight != null && !inFlight.IsCompleted)
+        {
+            await MID_HelperFunctions.DebugMessageAsync(
+                "⏳ Refresh already in flight - awaiting existing refresh",
+                LogLevel.Debug
+            );
+            return await AwaitInFlightRefreshAsync(inFlight);
+        }
+
+        // Check cooldown period - a caller arriving here means a refresh just
+        // completed (otherwise _inFlightRefresh above would still be live), so
+        // hand back that result rather than forcing the caller to treat it as a failure
         var timeSinceLastRefresh = DateTime.UtcNow - _lastRefreshAttempt;
         if (timeSinceLastRefresh.TotalSeconds < RefreshCooldownSeconds)
         {
@@ -143,15 +324,24 @@ public class SessionManager
                 $"⏳ Refresh in cooldown ({RefreshCooldownSeconds - (int)timeSinceLastRefresh.TotalSeconds}s remaining)",
                 LogLevel.Debug
             );
-            return null;
+            return _lastCompletedRefresh;
         }
 
         // Acquire lock (wait if another refresh is in progress)
         await _refreshLock.WaitAsync();
-        
+
         try
         {
-            // Double-check cooldown after acquiring lock
+            // Another caller may have started (and possibly finished) a refresh
+            // while we were waiting on the lock - piggyback on it if it's still running
+            inFlight = _inFlightRefresh;
+            if (inFlight != null && !inFlight.IsCompleted)
+            {
+                return await AwaitInFlightRefreshAsync(inFlight);
+            }
+
+            // Double-check cooldown after acquiring lock - again, reaching here
+            // means a refresh just completed, so surface its result instead of null
             timeSinceLastRefresh = DateTime.UtcNow - _lastRefreshAttempt;
             if (timeSinceLastRefresh.TotalSeconds < RefreshCooldownSeconds)
             {
@@ -159,46 +349,314 @@ public class SessionManager
                     "⏭️ Skipping refresh - another refresh just completed",
                     LogLevel.Debug
                 );
-                return null;
+                return _lastCompletedRefresh;
             }
 
-            await MID_HelperFunctions.DebugMessageAsync(
-                "🔄 Executing session refresh (locked)",
-                LogLevel.Info
-            );
+            // Claim the cross-tab/circuit lock before touching the network - another
+            // tab may already be mid-refresh with the same refresh token
+            if (!await TryClaimRefreshLockAsync())
+            {
+                await MID_HelperFunctions.DebugMessageAsync(
+                    "🔒 Refresh lock held by another tab - waiting for its result",
+                    LogLevel.Debug
+                );
 
-            _lastRefreshAttempt = DateTime.UtcNow;
-            var session = await refreshFunc();
+                var staleAccessToken = (await GetStoredSessionAsync())?.AccessToken;
+                return await WaitForCrossTabRefreshAsync(staleAccessToken);
+            }
 
-            if (session != null)
+            try
             {
-                await StoreSessionAsync(session);
-                
                 await MID_HelperFunctions.DebugMessageAsync(
-                    "✅ Session refreshed successfully",
+                    "🔄 Executing session refresh (locked)",
                     LogLevel.Info
                 );
+
+                _lastRefreshAttempt = DateTime.UtcNow;
+                var refreshTask = refreshFunc();
+                _inFlightRefresh = refreshTask;
+
+                var session = await refreshTask;
+                _lastCompletedRefresh = session;
+
+                if (session != null)
+                {
+                    await StoreSessionAsync(session);
+
+                    await MID_HelperFunctions.DebugMessageAsync(
+                        "✅ Session refreshed successfully",
+                        LogLevel.Info
+                    );
+                }
+                else
+                {
+                    await MID_HelperFunctions.DebugMessageAsync(
+                        "❌ Session refresh returned null",
+                        LogLevel.Warning
+                    );
+                }
+
+                return session;
+            }
+            finally
+            {
+                await ReleaseRefreshLockAsync();
+            }
+        }
+        catch (Exception ex)
+        {
+            await MID_HelperFunctions.LogExceptionAsync(ex, "Session refresh");
+
+            // A failed refresh invalidates whatever succeeded before it - otherwise a
+            // caller arriving during the cooldown window right after this failure would
+            // get handed back the last successful session instead of seeing the failure
+            _lastCompletedRefresh = null;
+
+            var classified = SessionErrorClassifier.Classify(ex);
+            if (classified is TransientRefreshException)
+            {
+                // Recoverable - leave the stored tokens intact so a later retry can succeed
+                _logger.LogWarning(ex, "Transient error during session refresh");
             }
             else
             {
+                // Terminal - the refresh token can't be used again, so there's
+                // nothing to preserve; wipe the session and force re-authentication
+                _logger.LogError(ex, "Terminal error during session refresh - invalidating session");
+                await ClearSessionAsync();
+                SessionInvalidated?.Invoke(classified);
+            }
+
+            throw classified;
+        }
+        finally
+        {
+            _inFlightRefresh = null;
+            _refreshLock.Release();
+        }
+    }
+
+    /// <summary>
+    /// Await an in-flight refresh task on behalf of a piggybacking caller. The task
+    /// itself is the raw refreshFunc() call and carries an unclassified exception if
+    /// it faults, so piggybackers classify it themselves rather than propagating the
+    /// raw exception - the lock-holder (not piggybackers) owns clearing the session
+    /// and raising SessionInvalidated, to avoid doing that once per piggybacker
+    /// </summary>
+    private static async Task<Session?> AwaitInFlightRefreshAsync(Task<Session?> inFlight)
+    {
+        try
+        {
+            return await inFlight;
+        }
+        catch (Exception ex)
+        {
+            throw SessionErrorClassifier.Classify(ex);
+        }
+    }
+
+    /// <summary>
+    /// Start a background loop that proactively refreshes the session shortly
+    /// before it expires, instead of relying on a caller to notice and ask.
+    /// Routes through ExecuteRefreshWithLockAsync so it still serializes against
+    /// reactive refreshes from other callers
+    /// </summary>
+    public void StartAutoRefresh(Func<Task<Session?>> refreshFunc, CancellationToken cancellationToken)
+    {
+        StopAutoRefresh();
+
+        _autoRefreshCts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
+        _autoRefreshTask = RunAutoRefreshLoopAsync(refreshFunc, _autoRefreshCts.Token);
+    }
+
+    /// <summary>
+    /// Stop the background auto-refresh loop and dispose its cancellation token
+    /// </summary>
+    public void StopAutoRefresh()
+    {
+        _autoRefreshCts?.Cancel();
+        _autoRefreshCts?.Dispose();
+        _autoRefreshCts = null;
+        _autoRefreshTask = null;
+    }
+
+    private async Task RunAutoRefreshLoopAsync(Func<Task<Session?>> refreshFunc, CancellationToken cancellationToken)
+    {
+        var consecutiveFailures = 0;
+
+        while (!cancellationToken.IsCancellationRequested)
+        {
+            try
+            {
+                var stored = await GetStoredSessionAsync();
+
+                if (stored != null && IsExpired(stored))
+                {
+                    await MID_HelperFunctions.DebugMessageAsync(
+                        "🚪 Session past its idle/absolute lifetime - ending auto-refresh without a doomed retry",
+                        LogLevel.Warning
+                    );
+                    await ClearSessionAsync();
+                    SessionExpired?.Invoke();
+                    return;
+                }
+
+                var delay = GetNextAutoRefreshDelay(stored?.ExpiresAt, consecutiveFailures);
+
+                await MID_HelperFunctions.DebugMessageAsync(
+                    $"⏰ Auto-refresh sleeping for {delay}",
+                    LogLevel.Debug
+                );
+
+                await Task.Delay(delay, cancellationToken);
+
+                var session = await ExecuteRefreshWithLockAsync(refreshFunc);
+
+                if (session != null)
+                {
+                    consecutiveFailures = 0;
+                    continue;
+                }
+
+                consecutiveFailures++;
+
+                await MID_HelperFunctions.DebugMessageAsync(
+                    $"❌ Auto-refresh attempt {consecutiveFailures} failed",
+                    LogLevel.Warning
+                );
+
+                if (consecutiveFailures >= AutoRefreshMaxConsecutiveFailures)
+                {
+                    await MID_HelperFunctions.DebugMessageAsync(
+                        "🚪 Auto-refresh exhausted retries - session considered expired",
+                        LogLevel.Error
+                    );
+                    SessionExpired?.Invoke();
+                    return;
+                }
+            }
+            catch (OperationCanceledException)
+            {
+                return;
+            }
+            catch (RefreshTokenReusedException)
+            {
+                // Terminal - ExecuteRefreshWithLockAsync already cleared the session
+                // and raised SessionInvalidated, so there's nothing left to retry
+                return;
+            }
+            catch (InvalidGrantException)
+            {
+                return;
+            }
+            catch (TransientRefreshException ex)
+            {
+                consecutiveFailures++;
+
                 await MID_HelperFunctions.DebugMessageAsync(
-                    "❌ Session refresh returned null",
+                    $"❌ Auto-refresh attempt {consecutiveFailures} failed transiently: {ex.Message}",
                     LogLevel.Warning
                 );
+
+                if (consecutiveFailures >= AutoRefreshMaxConsecutiveFailures)
+                {
+                    await MID_HelperFunctions.DebugMessageAsync(
+                        "🚪 Auto-refresh exhausted retries - session considered expired",
+                        LogLevel.Error
+                    );
+                    SessionExpired?.Invoke();
+                    return;
+                }
+            }
+            catch (Exception ex)
+            {
+                _logger.LogError(ex, "Error in auto-refresh loop");
             }
+        }
+    }
 
-            return session;
+    private static TimeSpan GetNextAutoRefreshDelay(DateTime? expiresAt, int consecutiveFailures)
+    {
+        if (consecutiveFailures > 0)
+        {
+            var backoffSeconds = Math.Min(
+                AutoRefreshMaxBackoffSeconds,
+                AutoRefreshBaseBackoffSeconds * Math.Pow(2, consecutiveFailures - 1));
+            return TimeSpan.FromSeconds(backoffSeconds);
+        }
+
+        if (expiresAt == null)
+        {
+            return TimeSpan.FromSeconds(AutoRefreshBaseBackoffSeconds);
+        }
+
+        var delay = expiresAt.Value - DateTime.UtcNow - TimeSpan.FromMinutes(5);
+        return delay > TimeSpan.Zero ? delay : TimeSpan.Zero;
+    }
+
+    /// <summary>
+    /// Attempt to atomically claim the cross-tab refresh lock. Unlike a localStorage
+    /// read-then-write, <see cref="IRefreshLockCoordinator"/> can't be won by two tabs
+    /// at once - <see cref="RefreshLockTtl"/> is how long this call is willing to wait
+    /// to acquire the lock (it's passed through as the Web Locks request's abort-signal
+    /// timeout), not a staleness threshold for reclaiming an abandoned lock
+    /// </summary>
+    private async Task<bool> TryClaimRefreshLockAsync()
+    {
+        try
+        {
+            return await _refreshLockCoordinator.TryAcquireAsync(RefreshLockName, _instanceId, RefreshLockTtl);
         }
         catch (Exception ex)
         {
-            await MID_HelperFunctions.LogExceptionAsync(ex, "Session refresh");
-            _logger.LogError(ex, "Error during locked session refresh");
-            return null;
+            _logger.LogError(ex, "Error claiming cross-tab refresh lock");
+            return false;
         }
-        finally
+    }
+
+    private async Task ReleaseRefreshLockAsync()
+    {
+        try
         {
-            _refreshLock.Release();
+            await _refreshLockCoordinator.ReleaseAsync(RefreshLockName, _instanceId);
+        }
+        catch (Exception ex)
+        {
+            _logger.LogError(ex, "Error releasing cross-tab refresh lock");
+        }
+    }
+
+    /// <summary>
+    /// Poll storage for the session another tab is refreshing, so every tab
+    /// converges on the one token that tab's StoreSessionAsync writes.
+    /// Detected by the access token itself changing - <c>LastActivityAt</c> is
+    /// unsuitable since RegisterActivityAsync bumps it on any user interaction,
+    /// refresh or not
+    /// </summary>
+    private async Task<Session?> WaitForCrossTabRefreshAsync(string? staleAccessToken)
+    {
+        for (var attempt = 0; attempt < CrossTabPollMaxAttempts; attempt++)
+        {
+            await Task.Delay(CrossTabPollInterval);
+
+            var stored = await GetStoredSessionAsync();
+            if (stored != null &&
+                !string.IsNullOrEmpty(stored.AccessToken) &&
+                stored.AccessToken != staleAccessToken)
+            {
+                return new Session
+                {
+                    AccessToken = stored.AccessToken,
+                    RefreshToken = stored.RefreshToken
+                };
+            }
         }
+
+        await MID_HelperFunctions.DebugMessageAsync(
+            "⏱️ Timed out waiting for another tab's refresh",
+            LogLevel.Warning
+        );
+        return null;
     }
 }
 
@@ -207,4 +665,6 @@ public class StoredSession
     public string AccessToken { get; set; } = string.Empty;
     public string RefreshToken { get; set; } = string.Empty;
     public DateTime? ExpiresAt { get; set; }
+    public DateTime? SessionStartedAt { get; set; }
+    public DateTime? LastActivityAt { get; set; }
 }
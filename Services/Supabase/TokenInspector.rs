@@ -0,0 +1,87 @@
+using System.Text;
+using System.Text.Json;
+
+namespace SubashaVentures.Services.Supabase;
+
+/// <summary>
+/// Reads expiry-related claims directly out of a JWT's payload, so callers can
+/// validate against what the token actually claims instead of trusting
+/// separately stored state that can drift or be tampered with
+/// </summary>
+public static class TokenInspector
+{
+    /// <summary>
+    /// Decode the `exp` claim of a JWT access token as a UTC DateTime.
+    /// Returns null if the token is missing, malformed, or has no `exp` claim
+    /// </summary>
+    public static DateTime? GetExpiry(string? jwt) => GetDateTimeClaim(jwt, "exp");
+
+    /// <summary>
+    /// Decode the `iat` (issued-at) claim of a JWT access token as a UTC DateTime
+    /// </summary>
+    public static DateTime? GetIssuedAt(string? jwt) => GetDateTimeClaim(jwt, "iat");
+
+    /// <summary>
+    /// Decode the `nbf` (not-before) claim of a JWT access token as a UTC DateTime
+    /// </summary>
+    public static DateTime? GetNotBefore(string? jwt) => GetDateTimeClaim(jwt, "nbf");
+
+    /// <summary>
+    /// Consistent "is this already past" check (UtcNow > expiration).
+    /// A null expiration - e.g. from a malformed or unparseable token - is
+    /// treated as expired rather than trusted
+    /// </summary>
+    public static bool HasExpired(DateTime? expiration)
+    {
+        if (expiration == null) return true;
+        return DateTime.UtcNow > expiration.Value;
+    }
+
+    private static DateTime? GetDateTimeClaim(string? jwt, string claimName)
+    {
+        var payload = DecodePayload(jwt);
+        if (payload == null) return null;
+
+        if (!payload.TryGetValue(claimName, out var claim)) return null;
+        if (claim.ValueKind != JsonValueKind.Number || !claim.TryGetInt64(out var secondsSinceEpoch))
+        {
+            return null;
+        }
+
+        return DateTimeOffset.FromUnixTimeSeconds(secondsSinceEpoch).UtcDateTime;
+    }
+
+    private static Dictionary<string, JsonElement>? DecodePayload(string? jwt)
+    {
+        if (string.IsNullOrEmpty(jwt)) return null;
+
+        try
+        {
+            var parts = jwt.Split('.');
+            if (parts.Length < 2) return null;
+
+            var payloadJson = Encoding.UTF8.GetString(Base64UrlDecode(parts[1]));
+            return JsonSerializer.Deserialize<Dictionary<string, JsonElement>>(payloadJson);
+        }
+        catch (Exception)
+        {
+            return null;
+        }
+    }
+
+    private static byte[] Base64UrlDecode(string input)
+    {
+        var padded = input.Replace('-', '+').Replace('_', '/');
+        switch (padded.Length % 4)
+        {
+            case 2:
+                padded += "==";
+                break;
+            case 3:
+                padded += "=";
+                break;
+        }
+
+        return Convert.FromBase64String(padded);
+    }
+}
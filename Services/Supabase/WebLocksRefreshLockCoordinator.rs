@@ -0,0 +1,33 @@
+using Microsoft.JSInterop;
+
+namespace SubashaVentures.Services.Supabase;
+
+/// <summary>
+/// Backs <see cref="IRefreshLockCoordinator"/> with the browser's Web Locks API
+/// (navigator.locks, see wwwroot/js/sessionLockInterop.js), which the browser
+/// itself arbitrates across tabs/circuits - unlike a localStorage read-then-write,
+/// a lock request can't be won by two tabs at once
+/// </summary>
+public sealed class WebLocksRefreshLockCoordinator : IRefreshLockCoordinator
+{
+    private readonly IJSRuntime _jsRuntime;
+
+    public WebLocksRefreshLockCoordinator(IJSRuntime jsRuntime)
+    {
+        _jsRuntime = jsRuntime;
+    }
+
+    public async Task<bool> TryAcquireAsync(string lockName, string ownerId, TimeSpan ttl)
+    {
+        return await _jsRuntime.InvokeAsync<bool>(
+            "sessionLockInterop.tryAcquireRefreshLock",
+            lockName,
+            ownerId,
+            (int)ttl.TotalMilliseconds);
+    }
+
+    public async Task ReleaseAsync(string lockName, string ownerId)
+    {
+        await _jsRuntime.InvokeVoidAsync("sessionLockInterop.releaseRefreshLock", ownerId);
+    }
+}
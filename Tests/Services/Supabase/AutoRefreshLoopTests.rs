@@ -0,0 +1,107 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using SubashaVentures.Services.Supabase;
+using Supabase.Gotrue;
+using Xunit;
+
+namespace SubashaVentures.Tests.Services.Supabase;
+
+public class AutoRefreshLoopTests
+{
+    [Fact]
+    public async Task StartAutoRefresh_ClearsAlreadyIdleSession_WithoutAttemptingARefresh()
+    {
+        var localStorage = new FakeLocalStorage();
+        var manager = new SessionManager(
+            localStorage,
+            NullLogger<SessionManager>.Instance,
+            new FakeRefreshLockCoordinator(),
+            slidingIdleWindow: TimeSpan.FromMinutes(30));
+
+        // Seed a session that's already past the sliding idle window, bypassing
+        // StoreSessionAsync since it no longer lets a fresh write look idle
+        await localStorage.SetItemAsync("supabase_access_token", BuildAccessToken(TimeSpan.FromHours(1)));
+        await localStorage.SetItemAsync("supabase_refresh_token", "refresh-token");
+        await localStorage.SetItemAsync("supabase_session_started_at", DateTime.UtcNow.AddHours(-1).ToString("o"));
+        await localStorage.SetItemAsync("supabase_last_activity_at", DateTime.UtcNow.AddMinutes(-45).ToString("o"));
+
+        var refreshCalls = 0;
+        var expiredSignal = new TaskCompletionSource();
+        manager.SessionExpired += () => expiredSignal.TrySetResult();
+
+        manager.StartAutoRefresh(
+            () =>
+            {
+                Interlocked.Increment(ref refreshCalls);
+                return Task.FromResult<Session?>(null);
+            },
+            CancellationToken.None);
+
+        await WaitWithTimeoutAsync(expiredSignal.Task, TimeSpan.FromSeconds(2));
+        manager.StopAutoRefresh();
+
+        Assert.Equal(0, refreshCalls);
+        Assert.Null(await manager.GetStoredSessionAsync());
+    }
+
+    [Fact]
+    public async Task StartAutoRefresh_TransientFailure_DoesNotRaiseSessionExpired()
+    {
+        var manager = new SessionManager(
+            new FakeLocalStorage(),
+            NullLogger<SessionManager>.Instance,
+            new FakeRefreshLockCoordinator());
+
+        await manager.StoreSessionAsync(BuildSession(TimeSpan.FromSeconds(1)));
+
+        var refreshCalls = 0;
+        var attempted = new TaskCompletionSource();
+        var expiredSignal = new TaskCompletionSource();
+        manager.SessionExpired += () => expiredSignal.TrySetResult();
+
+        manager.StartAutoRefresh(
+            () =>
+            {
+                Interlocked.Increment(ref refreshCalls);
+                attempted.TrySetResult();
+                throw new Exception("network timeout");
+            },
+            CancellationToken.None);
+
+        await WaitWithTimeoutAsync(attempted.Task, TimeSpan.FromSeconds(2));
+        manager.StopAutoRefresh();
+
+        Assert.True(refreshCalls >= 1);
+        Assert.False(expiredSignal.Task.IsCompleted);
+    }
+
+    private static async Task WaitWithTimeoutAsync(Task task, TimeSpan timeout)
+    {
+        var completed = await Task.WhenAny(task, Task.Delay(timeout));
+        Assert.Same(task, completed);
+    }
+
+    private static Session BuildSession(TimeSpan expiresIn)
+    {
+        return new Session
+        {
+            AccessToken = BuildAccessToken(expiresIn),
+            RefreshToken = "refresh-token"
+        };
+    }
+
+    private static string BuildAccessToken(TimeSpan expiresIn)
+    {
+        var exp = DateTimeOffset.UtcNow.Add(expiresIn).ToUnixTimeSeconds();
+        var header = Base64UrlEncode("{\"alg\":\"HS256\",\"typ\":\"JWT\"}");
+        var payload = Base64UrlEncode($"{{\"exp\":{exp}}}");
+        return $"{header}.{payload}.signature";
+    }
+
+    private static string Base64UrlEncode(string value)
+    {
+        return Convert.ToBase64String(System.Text.Encoding.UTF8.GetBytes(value))
+            .TrimEnd('=')
+            .Replace('+', '-')
+            .Replace('/', '_');
+    }
+}
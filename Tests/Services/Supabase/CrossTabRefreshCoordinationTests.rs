@@ -0,0 +1,89 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using SubashaVentures.Services.Supabase;
+using Supabase.Gotrue;
+using Xunit;
+
+namespace SubashaVentures.Tests.Services.Supabase;
+
+public class CrossTabRefreshCoordinationTests
+{
+    [Fact]
+    public async Task ExecuteRefreshWithLockAsync_SecondTab_WaitsForFirstTabsRefresh_InsteadOfRefreshingItself()
+    {
+        // Two SessionManager instances sharing the same browser storage and cross-tab
+        // lock, the way two tabs/circuits of the same session would
+        var sharedStorage = new FakeLocalStorage();
+        var sharedCoordinator = new FakeRefreshLockCoordinator();
+
+        var tab1 = new SessionManager(sharedStorage, NullLogger<SessionManager>.Instance, sharedCoordinator);
+        var tab2 = new SessionManager(sharedStorage, NullLogger<SessionManager>.Instance, sharedCoordinator);
+
+        await tab1.StoreSessionAsync(BuildSession(TimeSpan.FromHours(1)));
+
+        var gate = new TaskCompletionSource();
+        var tab1RefreshCalls = 0;
+        var tab2RefreshCalls = 0;
+
+        var tab1Refresh = tab1.ExecuteRefreshWithLockAsync(async () =>
+        {
+            Interlocked.Increment(ref tab1RefreshCalls);
+            await gate.Task;
+            return BuildSession(TimeSpan.FromHours(2));
+        });
+
+        // Give tab1 a moment to claim the cross-tab lock before tab2 tries
+        await Task.Delay(50);
+
+        var tab2Refresh = tab2.ExecuteRefreshWithLockAsync(() =>
+        {
+            Interlocked.Increment(ref tab2RefreshCalls);
+            return Task.FromResult<Session?>(BuildSession(TimeSpan.FromHours(3)));
+        });
+
+        gate.SetResult();
+        var results = await Task.WhenAll(tab1Refresh, tab2Refresh);
+
+        Assert.Equal(1, tab1RefreshCalls);
+        Assert.Equal(0, tab2RefreshCalls);
+        Assert.NotNull(results[0]);
+        Assert.Equal(results[0]!.AccessToken, results[1]!.AccessToken);
+    }
+
+    [Fact]
+    public async Task TryAcquireAsync_SecondOwner_CannotClaimWhileFirstHoldsIt()
+    {
+        var coordinator = new FakeRefreshLockCoordinator();
+
+        var firstClaimed = await coordinator.TryAcquireAsync("supabase_refresh_lock", "owner-1", TimeSpan.FromSeconds(30));
+        var secondClaimed = await coordinator.TryAcquireAsync("supabase_refresh_lock", "owner-2", TimeSpan.FromSeconds(30));
+
+        Assert.True(firstClaimed);
+        Assert.False(secondClaimed);
+
+        await coordinator.ReleaseAsync("supabase_refresh_lock", "owner-1");
+        var afterRelease = await coordinator.TryAcquireAsync("supabase_refresh_lock", "owner-2", TimeSpan.FromSeconds(30));
+
+        Assert.True(afterRelease);
+    }
+
+    private static Session BuildSession(TimeSpan expiresIn)
+    {
+        var exp = DateTimeOffset.UtcNow.Add(expiresIn).ToUnixTimeSeconds();
+        var header = Base64UrlEncode("{\"alg\":\"HS256\",\"typ\":\"JWT\"}");
+        var payload = Base64UrlEncode($"{{\"exp\":{exp}}}");
+
+        return new Session
+        {
+            AccessToken = $"{header}.{payload}.signature",
+            RefreshToken = "refresh-token"
+        };
+    }
+
+    private static string Base64UrlEncode(string value)
+    {
+        return Convert.ToBase64String(System.Text.Encoding.UTF8.GetBytes(value))
+            .TrimEnd('=')
+            .Replace('+', '-')
+            .Replace('/', '_');
+    }
+}
@@ -0,0 +1,30 @@
+using System.Collections.Concurrent;
+using SubashaVentures.Services.Storage;
+
+namespace SubashaVentures.Tests.Services.Supabase;
+
+/// <summary>
+/// In-memory stand-in for <see cref="IBlazorAppLocalStorageService"/>, used so
+/// SessionManager tests don't need a real browser/JS runtime
+/// </summary>
+public class FakeLocalStorage : IBlazorAppLocalStorageService
+{
+    private readonly ConcurrentDictionary<string, object?> _store = new();
+
+    public Task<T> GetItemAsync<T>(string key)
+    {
+        return Task.FromResult(_store.TryGetValue(key, out var value) ? (T)value! : default!);
+    }
+
+    public Task SetItemAsync<T>(string key, T value)
+    {
+        _store[key] = value;
+        return Task.CompletedTask;
+    }
+
+    public Task RemoveItemAsync(string key)
+    {
+        _store.TryRemove(key, out _);
+        return Task.CompletedTask;
+    }
+}
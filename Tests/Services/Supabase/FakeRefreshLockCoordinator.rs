@@ -0,0 +1,26 @@
+using System.Collections.Concurrent;
+using SubashaVentures.Services.Supabase;
+
+namespace SubashaVentures.Tests.Services.Supabase;
+
+/// <summary>
+/// In-memory stand-in for <see cref="IRefreshLockCoordinator"/> that grants the
+/// lock immediately to whichever owner asks first - enough to exercise
+/// SessionManager's own in-process coordination without a real browser
+/// </summary>
+public class FakeRefreshLockCoordinator : IRefreshLockCoordinator
+{
+    private readonly ConcurrentDictionary<string, string> _owners = new();
+
+    public Task<bool> TryAcquireAsync(string lockName, string ownerId, TimeSpan ttl)
+    {
+        var acquired = _owners.TryAdd(lockName, ownerId);
+        return Task.FromResult(acquired);
+    }
+
+    public Task ReleaseAsync(string lockName, string ownerId)
+    {
+        _owners.TryRemove(new KeyValuePair<string, string>(lockName, ownerId));
+        return Task.CompletedTask;
+    }
+}
@@ -0,0 +1,46 @@
+using SubashaVentures.Services.Supabase;
+using Xunit;
+
+namespace SubashaVentures.Tests.Services.Supabase;
+
+public class SessionErrorClassifierTests
+{
+    [Theory]
+    [InlineData("refresh_token_already_used")]
+    [InlineData("Invalid Refresh Token: Already Used")]
+    [InlineData("refresh token has already been used")]
+    public void Classify_ReturnsRefreshTokenReusedException_ForReuseError(string message)
+    {
+        var classified = SessionErrorClassifier.Classify(new Exception(message));
+
+        Assert.IsType<RefreshTokenReusedException>(classified);
+    }
+
+    [Theory]
+    [InlineData("invalid_grant")]
+    [InlineData("Invalid Refresh Token: malformed")]
+    public void Classify_ReturnsInvalidGrantException_ForRejectedGrant(string message)
+    {
+        var classified = SessionErrorClassifier.Classify(new Exception(message));
+
+        Assert.IsType<InvalidGrantException>(classified);
+    }
+
+    [Fact]
+    public void Classify_ReturnsTransientRefreshException_ForUnrecognizedError()
+    {
+        var classified = SessionErrorClassifier.Classify(new Exception("network timeout"));
+
+        Assert.IsType<TransientRefreshException>(classified);
+    }
+
+    [Fact]
+    public void Classify_PreservesOriginalExceptionAsInnerException()
+    {
+        var original = new Exception("invalid_grant");
+
+        var classified = SessionErrorClassifier.Classify(original);
+
+        Assert.Same(original, classified.InnerException);
+    }
+}
@@ -0,0 +1,162 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using SubashaVentures.Services.Supabase;
+using Xunit;
+
+namespace SubashaVentures.Tests.Services.Supabase;
+
+public class SessionLifetimeTests
+{
+    [Fact]
+    public void ShouldRefresh_ReturnsFalse_WhenFarFromExpiry()
+    {
+        var manager = CreateSessionManager();
+        var session = BuildStoredSession(
+            expiresIn: TimeSpan.FromMinutes(30),
+            lastActivityAgo: TimeSpan.FromMinutes(1));
+
+        Assert.False(manager.ShouldRefresh(session));
+    }
+
+    [Fact]
+    public void ShouldRefresh_ReturnsTrue_WhenWithinFiveMinutesOfExpiryAndRecentlyActive()
+    {
+        var manager = CreateSessionManager();
+        var session = BuildStoredSession(
+            expiresIn: TimeSpan.FromMinutes(1),
+            lastActivityAgo: TimeSpan.FromMinutes(1));
+
+        Assert.True(manager.ShouldRefresh(session));
+    }
+
+    [Fact]
+    public void ShouldRefresh_ReturnsFalse_WhenNearExpiryButIdleBeyondSlidingWindow()
+    {
+        var manager = CreateSessionManager(slidingIdleWindow: TimeSpan.FromMinutes(30));
+        var session = BuildStoredSession(
+            expiresIn: TimeSpan.FromMinutes(1),
+            lastActivityAgo: TimeSpan.FromMinutes(45));
+
+        Assert.False(manager.ShouldRefresh(session));
+    }
+
+    [Fact]
+    public void IsExpired_ReturnsFalse_ForFreshActiveSession()
+    {
+        var manager = CreateSessionManager();
+        var session = BuildStoredSession(
+            expiresIn: TimeSpan.FromMinutes(30),
+            lastActivityAgo: TimeSpan.FromMinutes(1),
+            startedAgo: TimeSpan.FromMinutes(1));
+
+        Assert.False(manager.IsExpired(session));
+    }
+
+    [Fact]
+    public void IsExpired_ReturnsTrue_WhenIdleBeyondSlidingWindow()
+    {
+        var manager = CreateSessionManager(slidingIdleWindow: TimeSpan.FromMinutes(30));
+        var session = BuildStoredSession(
+            expiresIn: TimeSpan.FromMinutes(30),
+            lastActivityAgo: TimeSpan.FromMinutes(45),
+            startedAgo: TimeSpan.FromMinutes(45));
+
+        Assert.True(manager.IsExpired(session));
+    }
+
+    [Fact]
+    public void IsExpired_ReturnsTrue_WhenBeyondAbsoluteLifetimeDespiteRecentActivity()
+    {
+        var manager = CreateSessionManager(absoluteSessionLifetime: TimeSpan.FromDays(7));
+        var session = BuildStoredSession(
+            expiresIn: TimeSpan.FromMinutes(30),
+            lastActivityAgo: TimeSpan.FromMinutes(1),
+            startedAgo: TimeSpan.FromDays(8));
+
+        Assert.True(manager.IsExpired(session));
+    }
+
+    [Fact]
+    public void IsExpired_ReturnsTrue_ForNullSession()
+    {
+        var manager = CreateSessionManager();
+
+        Assert.True(manager.IsExpired(null));
+    }
+
+    [Fact]
+    public async Task StoreSessionAsync_OnRefresh_LeavesLastActivityAt_WhileRegisterActivityAsyncExtendsIt()
+    {
+        // Regression test: StoreSessionAsync must not stamp LastActivityAt on a
+        // refresh, or an idle session kept "alive" only by the background
+        // auto-refresh loop would never trip IsExpired's idle check.
+        var localStorage = new FakeLocalStorage();
+        var manager = new SessionManager(
+            localStorage,
+            NullLogger<SessionManager>.Instance,
+            new FakeRefreshLockCoordinator(),
+            slidingIdleWindow: TimeSpan.FromMinutes(30));
+
+        await manager.StoreSessionAsync(BuildSession(TimeSpan.FromHours(1)));
+        var afterLogin = await manager.GetStoredSessionAsync();
+        var loginActivity = afterLogin!.LastActivityAt;
+
+        await manager.StoreSessionAsync(BuildSession(TimeSpan.FromHours(2)));
+        var afterRefresh = await manager.GetStoredSessionAsync();
+
+        Assert.Equal(loginActivity, afterRefresh!.LastActivityAt);
+
+        await manager.RegisterActivityAsync();
+        var afterActivity = await manager.GetStoredSessionAsync();
+
+        Assert.True(afterActivity!.LastActivityAt > loginActivity);
+    }
+
+    private static SessionManager CreateSessionManager(
+        TimeSpan? slidingIdleWindow = null,
+        TimeSpan? absoluteSessionLifetime = null)
+    {
+        return new SessionManager(
+            new FakeLocalStorage(),
+            NullLogger<SessionManager>.Instance,
+            new FakeRefreshLockCoordinator(),
+            slidingIdleWindow,
+            absoluteSessionLifetime);
+    }
+
+    private static StoredSession BuildStoredSession(
+        TimeSpan expiresIn,
+        TimeSpan lastActivityAgo,
+        TimeSpan? startedAgo = null)
+    {
+        var now = DateTime.UtcNow;
+        return new StoredSession
+        {
+            AccessToken = "access-token",
+            RefreshToken = "refresh-token",
+            ExpiresAt = now + expiresIn,
+            LastActivityAt = now - lastActivityAgo,
+            SessionStartedAt = now - (startedAgo ?? lastActivityAgo)
+        };
+    }
+
+    private static Supabase.Gotrue.Session BuildSession(TimeSpan expiresIn)
+    {
+        var exp = DateTimeOffset.UtcNow.Add(expiresIn).ToUnixTimeSeconds();
+        var header = Base64UrlEncode("{\"alg\":\"HS256\",\"typ\":\"JWT\"}");
+        var payload = Base64UrlEncode($"{{\"exp\":{exp}}}");
+
+        return new Supabase.Gotrue.Session
+        {
+            AccessToken = $"{header}.{payload}.signature",
+            RefreshToken = "refresh-token"
+        };
+    }
+
+    private static string Base64UrlEncode(string value)
+    {
+        return Convert.ToBase64String(System.Text.Encoding.UTF8.GetBytes(value))
+            .TrimEnd('=')
+            .Replace('+', '-')
+            .Replace('/', '_');
+    }
+}
@@ -0,0 +1,104 @@
+using System.Text;
+using Microsoft.Extensions.Logging.Abstractions;
+using SubashaVentures.Services.Supabase;
+using Supabase.Gotrue;
+using Xunit;
+
+namespace SubashaVentures.Tests.Services.Supabase;
+
+public class SessionManagerRefreshTests
+{
+    [Fact]
+    public async Task ExecuteRefreshWithLockAsync_CoalescesConcurrentCallers_IntoASingleRefresh()
+    {
+        var manager = CreateSessionManager();
+        var refreshCalls = 0;
+        var gate = new TaskCompletionSource();
+
+        async Task<Session?> RefreshFunc()
+        {
+            Interlocked.Increment(ref refreshCalls);
+            await gate.Task;
+            return BuildSession();
+        }
+
+        var first = manager.ExecuteRefreshWithLockAsync(RefreshFunc);
+        var second = manager.ExecuteRefreshWithLockAsync(RefreshFunc);
+
+        gate.SetResult();
+        var results = await Task.WhenAll(first, second);
+
+        Assert.Equal(1, refreshCalls);
+        Assert.NotNull(results[0]);
+        Assert.Same(results[0], results[1]);
+    }
+
+    [Fact]
+    public async Task ExecuteRefreshWithLockAsync_DuringCooldown_ReturnsLastCompletedRefreshInsteadOfNull()
+    {
+        var manager = CreateSessionManager();
+        var refreshCalls = 0;
+
+        Task<Session?> RefreshFunc()
+        {
+            Interlocked.Increment(ref refreshCalls);
+            return Task.FromResult<Session?>(BuildSession());
+        }
+
+        var firstResult = await manager.ExecuteRefreshWithLockAsync(RefreshFunc);
+        var secondResult = await manager.ExecuteRefreshWithLockAsync(RefreshFunc);
+
+        Assert.Equal(1, refreshCalls);
+        Assert.NotNull(firstResult);
+        Assert.Same(firstResult, secondResult);
+    }
+
+    [Fact]
+    public async Task ExecuteRefreshWithLockAsync_DuringCooldownAfterAFailedRefresh_DoesNotReturnAStalePriorSession()
+    {
+        var manager = CreateSessionManager();
+
+        Task<Session?> FailingRefreshFunc() => throw new Exception("network timeout");
+
+        // First-ever attempt has no cooldown to wait out (_lastRefreshAttempt starts
+        // at DateTime.MinValue), so this genuinely exercises the refresh failing
+        await Assert.ThrowsAsync<TransientRefreshException>(
+            () => manager.ExecuteRefreshWithLockAsync(FailingRefreshFunc));
+
+        // A caller arriving during the resulting cooldown window must see null, not
+        // whatever _lastCompletedRefresh happened to hold from before the failure
+        var duringCooldown = await manager.ExecuteRefreshWithLockAsync(
+            () => Task.FromResult<Session?>(BuildSession()));
+
+        Assert.Null(duringCooldown);
+    }
+
+    private static SessionManager CreateSessionManager()
+    {
+        return new SessionManager(
+            new FakeLocalStorage(),
+            NullLogger<SessionManager>.Instance,
+            new FakeRefreshLockCoordinator());
+    }
+
+    private static Session BuildSession()
+    {
+        var exp = DateTimeOffset.UtcNow.AddHours(1).ToUnixTimeSeconds();
+        var header = Base64UrlEncode("{\"alg\":\"HS256\",\"typ\":\"JWT\"}");
+        var payload = Base64UrlEncode($"{{\"exp\":{exp}}}");
+
+        return new Session
+        {
+            AccessToken = $"{header}.{payload}.signature",
+            RefreshToken = "refresh-token"
+        };
+    }
+
+    private static string Base64UrlEncode(string value)
+    {
+        return Convert.ToBase64String(Encoding.UTF8.GetBytes(value))
+            .TrimEnd('=')
+            .Replace('+', '-')
+            .Replace('/', '_');
+    }
+}
@@ -0,0 +1,82 @@
+using System.Text;
+using SubashaVentures.Services.Supabase;
+using Xunit;
+
+namespace SubashaVentures.Tests.Services.Supabase;
+
+public class TokenInspectorTests
+{
+    [Fact]
+    public void GetExpiry_ReturnsUtcDateTime_ForValidToken()
+    {
+        var expSeconds = new DateTimeOffset(2030, 1, 1, 0, 0, 0, TimeSpan.Zero).ToUnixTimeSeconds();
+        var jwt = BuildJwt($"{{\"exp\":{expSeconds}}}");
+
+        var expiry = TokenInspector.GetExpiry(jwt);
+
+        Assert.Equal(new DateTime(2030, 1, 1, 0, 0, 0, DateTimeKind.Utc), expiry);
+    }
+
+    [Theory]
+    [InlineData(null)]
+    [InlineData("")]
+    [InlineData("not-a-jwt")]
+    [InlineData("only.one-part")]
+    [InlineData("header.not-base64url!!!.signature")]
+    public void GetExpiry_ReturnsNull_ForMalformedToken(string? jwt)
+    {
+        Assert.Null(TokenInspector.GetExpiry(jwt));
+    }
+
+    [Fact]
+    public void GetExpiry_ReturnsNull_WhenExpClaimMissing()
+    {
+        var jwt = BuildJwt("{}");
+
+        Assert.Null(TokenInspector.GetExpiry(jwt));
+    }
+
+    [Fact]
+    public void GetIssuedAt_ReturnsUtcDateTime_ForValidToken()
+    {
+        var iatSeconds = new DateTimeOffset(2020, 6, 15, 12, 0, 0, TimeSpan.Zero).ToUnixTimeSeconds();
+        var jwt = BuildJwt($"{{\"iat\":{iatSeconds}}}");
+
+        var issuedAt = TokenInspector.GetIssuedAt(jwt);
+
+        Assert.Equal(new DateTime(2020, 6, 15, 12, 0, 0, DateTimeKind.Utc), issuedAt);
+    }
+
+    [Fact]
+    public void HasExpired_ReturnsTrue_ForNullExpiration()
+    {
+        Assert.True(TokenInspector.HasExpired(null));
+    }
+
+    [Fact]
+    public void HasExpired_ReturnsTrue_WhenExpirationIsInThePast()
+    {
+        Assert.True(TokenInspector.HasExpired(DateTime.UtcNow.AddMinutes(-1)));
+    }
+
+    [Fact]
+    public void HasExpired_ReturnsFalse_WhenExpirationIsInTheFuture()
+    {
+        Assert.False(TokenInspector.HasExpired(DateTime.UtcNow.AddMinutes(5)));
+    }
+
+    private static string BuildJwt(string payloadJson)
+    {
+        var header = Base64UrlEncode("{\"alg\":\"HS256\",\"typ\":\"JWT\"}");
+        var payload = Base64UrlEncode(payloadJson);
+        return $"{header}.{payload}.signature";
+    }
+
+    private static string Base64UrlEncode(string value)
+    {
+        return Convert.ToBase64String(Encoding.UTF8.GetBytes(value))
+            .TrimEnd('=')
+            .Replace('+', '-')
+            .Replace('/', '_');
+    }
+}